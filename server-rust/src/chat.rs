@@ -0,0 +1,84 @@
+use spacetimedb::{spacetimedb_lib::Identity, ReducerContext, Table, Timestamp};
+
+// Bring the players trait into scope so senders can be validated.
+use crate::players::players;
+// Bring the config trait into scope so the retention window is tunable.
+use crate::config::config;
+
+/// Maximum accepted length of a single chat message, in bytes.
+const MAX_MESSAGE_LEN: usize = 1024;
+
+/// Public chat feed. Clients subscribe and filter by `channel`.
+#[spacetimedb::table(name = messages, public)]
+#[derive(Debug, Clone)]
+pub struct Message {
+    #[primary_key]
+    #[auto_inc]
+    pub message_id: u32,
+    pub sender: Identity,
+    #[index(btree)]
+    pub channel: String,
+    pub text: String,
+    pub sent_at: Timestamp,
+}
+
+/// Reducer to post a message to a channel.
+#[spacetimedb::reducer]
+pub fn send_message(ctx: &ReducerContext, channel: String, text: String) -> Result<(), String> {
+    ctx.db
+        .players()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or("Player not found")?;
+
+    if text.is_empty() {
+        return Err("Message text must not be empty".to_string());
+    }
+    if text.len() > MAX_MESSAGE_LEN {
+        return Err("Message text exceeds maximum length".to_string());
+    }
+
+    ctx.db.messages().try_insert(Message {
+        message_id: 0,
+        sender: ctx.sender,
+        channel,
+        text,
+        sent_at: ctx.timestamp,
+    })?;
+
+    crate::players::touch_player(ctx);
+
+    Ok(())
+}
+
+/// Scheduled reducer that drops messages older than the configured retention
+/// window so the history table doesn't grow unbounded.
+#[spacetimedb::reducer]
+pub fn prune_messages(
+    ctx: &ReducerContext,
+    _timer: crate::timers::PruneMessagesTimer,
+) -> Result<(), String> {
+    let retention_ms = ctx
+        .db
+        .config()
+        .id()
+        .find(&0)
+        .map(|config| config.message_retention_ms)
+        .unwrap_or(0);
+    if retention_ms == 0 {
+        return Ok(());
+    }
+
+    let cutoff = ctx.timestamp.to_micros_since_unix_epoch() - (retention_ms as i64) * 1000;
+    let expired: Vec<_> = ctx
+        .db
+        .messages()
+        .iter()
+        .filter(|msg| msg.sent_at.to_micros_since_unix_epoch() < cutoff)
+        .collect();
+    for msg in expired {
+        ctx.db.messages().message_id().delete(&msg.message_id);
+    }
+
+    Ok(())
+}
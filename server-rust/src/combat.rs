@@ -0,0 +1,101 @@
+use spacetimedb::{ReducerContext, Table};
+
+// Bring the generated table helper traits into scope.
+use crate::types::character_stats;
+use crate::players::players;
+use crate::players::player_characters;
+use crate::entities::entities;
+use crate::components::{character_owner, spawn_state, transforms};
+
+/// Authorize a health mutation: allow the module owner (trusted system paths
+/// such as NPC AI) or the player who owns the character. Anyone else is
+/// rejected so clients can't damage or heal arbitrary characters.
+fn authorize_character(ctx: &ReducerContext, character_id: u32) -> Result<(), String> {
+    if ctx.sender == ctx.identity() {
+        return Ok(());
+    }
+
+    let pc = ctx
+        .db
+        .player_characters()
+        .character_id()
+        .find(&character_id)
+        .ok_or("Character not found")?;
+    let player = ctx
+        .db
+        .players()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or("Player not found")?;
+
+    if pc.player_id != player.player_id {
+        return Err("Not authorized to modify this character".to_string());
+    }
+
+    Ok(())
+}
+
+/// Apply damage to a character, clamping health at 0 and retiring the
+/// character's entity when it dies.
+#[spacetimedb::reducer]
+pub fn apply_damage(ctx: &ReducerContext, character_id: u32, amount: f32) -> Result<(), String> {
+    authorize_character(ctx, character_id)?;
+
+    let mut stats = ctx
+        .db
+        .character_stats()
+        .character_id()
+        .find(&character_id)
+        .ok_or("Character stats not found")?;
+
+    stats.health = (stats.health - amount).clamp(0.0, stats.max_health);
+    let died = stats.health <= 0.0;
+    ctx.db.character_stats().character_id().update(stats);
+
+    if died {
+        retire_character(ctx, character_id)?;
+    }
+
+    Ok(())
+}
+
+/// Restore health to a character, clamping at its `max_health`.
+#[spacetimedb::reducer]
+pub fn heal(ctx: &ReducerContext, character_id: u32, amount: f32) -> Result<(), String> {
+    authorize_character(ctx, character_id)?;
+
+    let mut stats = ctx
+        .db
+        .character_stats()
+        .character_id()
+        .find(&character_id)
+        .ok_or("Character stats not found")?;
+
+    stats.health = (stats.health + amount).clamp(0.0, stats.max_health);
+    ctx.db.character_stats().character_id().update(stats);
+
+    Ok(())
+}
+
+/// Retire a dead character: delete its live entity, every component keyed by
+/// that entity, and its stat block, then remove the character record so the
+/// player can `respawn` a fresh one. Death is distinct from the offline-player
+/// migration — the player is still connected, so the character is removed
+/// outright rather than stashed in the offline tables.
+fn retire_character(ctx: &ReducerContext, character_id: u32) -> Result<(), String> {
+    let pc = ctx
+        .db
+        .player_characters()
+        .character_id()
+        .find(&character_id)
+        .ok_or("Character not found")?;
+
+    ctx.db.entities().entity_id().delete(&pc.entity_id);
+    ctx.db.transforms().entity_id().delete(&pc.entity_id);
+    ctx.db.spawn_state().entity_id().delete(&pc.entity_id);
+    ctx.db.character_owner().entity_id().delete(&pc.entity_id);
+    ctx.db.character_stats().character_id().delete(pc.character_id);
+    ctx.db.player_characters().character_id().delete(pc.character_id);
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::types::Transform;
+
+/// Spatial component: the authoritative input transform for an entity.
+/// Any entity — player pawn, NPC, projectile — may carry one.
+#[spacetimedb::table(name = transforms, public)]
+#[spacetimedb::table(name = offline_transforms)]
+#[derive(Debug, Clone)]
+pub struct TransformComponent {
+    #[primary_key]
+    pub entity_id: u32,
+    pub transform: Transform,
+    /// Monotonic counter bumped whenever `transform` actually changes.
+    /// `move_all_players` compares it against `Entity::applied_version` to
+    /// skip writes for stationary entities.
+    pub transform_version: u64,
+}
+
+/// Field-by-field equality for transforms, used to avoid bumping
+/// `transform_version` on identical input resends.
+pub fn transform_eq(a: &Transform, b: &Transform) -> bool {
+    a.x == b.x
+        && a.y == b.y
+        && a.z == b.z
+        && a.yaw == b.yaw
+        && a.pitch == b.pitch
+        && a.roll == b.roll
+}
+
+/// Spawn component: whether an entity still needs its client-side spawn.
+#[spacetimedb::table(name = spawn_state, public)]
+#[derive(Debug, Clone)]
+pub struct SpawnState {
+    #[primary_key]
+    pub entity_id: u32,
+    pub needs_spawn: bool,
+}
+
+/// Ownership component linking an entity back to the player that controls it.
+#[spacetimedb::table(name = character_owner, public)]
+#[derive(Debug, Clone)]
+pub struct CharacterOwner {
+    #[primary_key]
+    pub entity_id: u32,
+    #[index(btree)]
+    pub player_id: u32,
+}
@@ -0,0 +1,94 @@
+use crate::types::Transform;
+use spacetimedb::{ReducerContext, Table};
+
+// Bring the move timer helper trait into scope so the interval can be rescheduled.
+use crate::timers::move_all_players_timer;
+
+/// Global server configuration singleton. Row `id = 0` always holds the live
+/// values; clients subscribe to the public table to read the MOTD.
+#[spacetimedb::table(name = config, public)]
+#[derive(Debug, Clone)]
+pub struct Config {
+    #[primary_key]
+    pub id: u32,
+    pub motd: String,
+    pub move_interval_ms: u64,
+    pub message_retention_ms: u64,
+    pub max_inactivity_ms: u64,
+    /// Maximum distance between a character and a floor item for `pickup_item`.
+    pub pickup_radius: f32,
+    pub spawn_point: Transform,
+}
+
+/// Helper producing the default configuration row inserted by `init`.
+pub fn default_config() -> Config {
+    Config {
+        id: 0,
+        motd: String::from("Welcome!"),
+        move_interval_ms: 50,
+        // Retain one hour of chat history by default.
+        message_retention_ms: 60 * 60 * 1000,
+        // Reclaim players idle for more than five minutes.
+        max_inactivity_ms: 5 * 60 * 1000,
+        // Characters must be within 3 units of an item to pick it up.
+        pickup_radius: 3.0,
+        spawn_point: Transform {
+            x: 0.0,
+            y: 0.0,
+            z: 100.0,
+            yaw: 0.0,
+            pitch: 0.0,
+            roll: 0.0,
+        },
+    }
+}
+
+/// Owner-gated reducer to update server-wide configuration.
+/// Rescheduling the `MoveAllPlayersTimer` picks up a changed tick interval.
+#[spacetimedb::reducer]
+pub fn set_config(
+    ctx: &ReducerContext,
+    motd: String,
+    move_interval_ms: u64,
+    message_retention_ms: u64,
+    max_inactivity_ms: u64,
+    pickup_radius: f32,
+    spawn_point: Transform,
+) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Only the module owner may set config".to_string());
+    }
+
+    let mut config = ctx
+        .db
+        .config()
+        .id()
+        .find(&0)
+        .ok_or("Config not initialized")?;
+    config.motd = motd;
+    config.move_interval_ms = move_interval_ms;
+    config.message_retention_ms = message_retention_ms;
+    config.max_inactivity_ms = max_inactivity_ms;
+    config.pickup_radius = pickup_radius;
+    config.spawn_point = spawn_point;
+    ctx.db.config().id().update(config);
+
+    reschedule_move_timer(ctx, move_interval_ms);
+
+    Ok(())
+}
+
+/// Reconcile the move timer's interval with `move_interval_ms`, updating the
+/// scheduled row only when the cadence has actually changed.
+pub fn reschedule_move_timer(ctx: &ReducerContext, move_interval_ms: u64) {
+    let desired = crate::timers::interval_at(move_interval_ms);
+    for mut timer in ctx.db.move_all_players_timer().iter() {
+        if crate::timers::interval_millis(&timer.scheduled_at) != Some(move_interval_ms) {
+            timer.scheduled_at = desired.clone();
+            ctx.db
+                .move_all_players_timer()
+                .scheduled_id()
+                .update(timer);
+        }
+    }
+}
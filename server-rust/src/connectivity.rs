@@ -1,4 +1,6 @@
-use crate::timers::default_move_all_players_interval;
+use crate::timers::{
+    default_idle_sweep_interval, default_prune_messages_interval, move_all_players_interval,
+};
 use spacetimedb::{ReducerContext, Table};
 
 // Bring the generated table helper traits into scope so ctx.db.<table>() helpers exist.
@@ -7,7 +9,15 @@ use crate::players::offline_players;
 use crate::players::player_characters;
 use crate::players::offline_player_characters;
 use crate::entities::entities;
+use crate::components::{
+    character_owner, offline_transforms, spawn_state, transforms, CharacterOwner, SpawnState,
+    TransformComponent,
+};
+use crate::types::{character_stats, offline_character_stats};
 use crate::timers::move_all_players_timer;
+use crate::timers::prune_messages_timer;
+use crate::timers::idle_sweep_timer;
+use crate::config::{config, default_config};
 
 // Import spawn helper from players module
 use crate::players::spawn_player_initial_player_character;
@@ -16,26 +26,42 @@ use crate::players::spawn_player_initial_player_character;
 #[spacetimedb::reducer(init)]
 pub fn init(ctx: &ReducerContext) -> Result<(), String> {
     log::info!("Initializing...");
+    let config = ctx.db.config().try_insert(default_config())?;
     ctx.db
         .move_all_players_timer()
-        .try_insert(default_move_all_players_interval())?;
+        .try_insert(move_all_players_interval(config.move_interval_ms))?;
+    ctx.db
+        .prune_messages_timer()
+        .try_insert(default_prune_messages_interval())?;
+    ctx.db
+        .idle_sweep_timer()
+        .try_insert(default_idle_sweep_interval())?;
     Ok(())
 }
 
 /// client_connected: restore from offline or create player row
 #[spacetimedb::reducer(client_connected)]
 pub fn connect(ctx: &ReducerContext) -> Result<(), String> {
-    if let Some(player) = ctx.db.offline_players().identity().find(&ctx.sender) {
+    if let Some(mut player) = ctx.db.offline_players().identity().find(&ctx.sender) {
+        player.last_active = ctx.timestamp;
         ctx.db.players().try_insert(player.clone())?;
         ctx.db.offline_players().identity().delete(&player.identity);
 
-        // restore offline characters for this player
-        for pc in ctx.db.offline_player_characters().player_id().filter(&player.player_id) {
+        // restore offline characters for this player; collect first so we
+        // aren't deleting from the table we're iterating.
+        let offline_chars: Vec<_> = ctx
+            .db
+            .offline_player_characters()
+            .player_id()
+            .filter(&player.player_id)
+            .collect();
+        for pc in offline_chars {
             ctx.db
                 .offline_player_characters()
                 .character_id()
                 .delete(pc.character_id);
             ctx.db.player_characters().try_insert(pc.clone())?;
+            restore_character_components(ctx, &pc)?;
         }
     } else {
         // create a new blank player row
@@ -43,6 +69,7 @@ pub fn connect(ctx: &ReducerContext) -> Result<(), String> {
             identity: ctx.sender.clone(),
             player_id: 0,
             display_name: String::new(),
+            last_active: ctx.timestamp,
         })?;
 
         // create initial PlayerCharacter if no existing characters
@@ -63,24 +90,139 @@ pub fn disconnect(ctx: &ReducerContext) -> Result<(), String> {
         .identity()
         .find(&ctx.sender)
         .ok_or("Player not found")?;
+
+    move_player_offline(ctx, player)
+}
+
+/// Scheduled sweep that migrates players idle beyond `max_inactivity_ms` to the
+/// offline tables, reclaiming world resources from zombie connections.
+#[spacetimedb::reducer]
+pub fn idle_sweep(
+    ctx: &ReducerContext,
+    _timer: crate::timers::IdleSweepTimer,
+) -> Result<(), String> {
+    let max_inactivity_ms = ctx
+        .db
+        .config()
+        .id()
+        .find(&0)
+        .map(|config| config.max_inactivity_ms)
+        .unwrap_or(0);
+    if max_inactivity_ms == 0 {
+        return Ok(());
+    }
+
+    let cutoff = ctx.timestamp.to_micros_since_unix_epoch() - (max_inactivity_ms as i64) * 1000;
+    let stale: Vec<_> = ctx
+        .db
+        .players()
+        .iter()
+        .filter(|player| player.last_active.to_micros_since_unix_epoch() < cutoff)
+        .collect();
+    for player in stale {
+        move_player_offline(ctx, player)?;
+    }
+
+    Ok(())
+}
+
+/// Shared offline-migration logic: stash the player, then migrate each of its
+/// characters to the offline tables.
+fn move_player_offline(ctx: &ReducerContext, player: crate::players::Player) -> Result<(), String> {
     let player_id = player.player_id;
+    let identity = player.identity;
 
-    ctx.db.offline_players().try_insert(player.clone())?;
-    ctx.db.players().identity().delete(&ctx.sender);
+    ctx.db.offline_players().try_insert(player)?;
+    ctx.db.players().identity().delete(&identity);
 
-    for pc in ctx.db.player_characters().player_id().filter(&player_id) {
-        // ensure entity exists (or skip)
-        let _entity = ctx
-            .db
-            .entities()
-            .entity_id()
-            .find(&pc.entity_id)
-            .ok_or("Entity not found")?;
-
-        // delete live entity, move character to offline
-        ctx.db.entities().entity_id().delete(&pc.entity_id);
-        ctx.db.offline_player_characters().try_insert(pc.clone())?;
-        ctx.db.player_characters().character_id().delete(pc.character_id);
+    // Collect first so we aren't deleting from the table we're iterating.
+    let characters: Vec<_> = ctx
+        .db
+        .player_characters()
+        .player_id()
+        .filter(&player_id)
+        .collect();
+    for pc in characters {
+        // Skip characters with no live entity rather than aborting the whole
+        // batch on a single bad row — otherwise one zombie poisons the entire
+        // sweep.
+        if ctx.db.entities().entity_id().find(&pc.entity_id).is_none() {
+            continue;
+        }
+        stash_character_offline(ctx, &pc)?;
+    }
+
+    Ok(())
+}
+
+/// Migrate a single character to the offline tables: stash its transform and
+/// stat block, then delete the live entity and every component keyed by the
+/// entity so nothing is orphaned. Shared by disconnect and the idle sweep so
+/// those paths stay consistent.
+pub fn stash_character_offline(
+    ctx: &ReducerContext,
+    pc: &crate::players::PlayerCharacter,
+) -> Result<(), String> {
+    if let Some(transform) = ctx.db.transforms().entity_id().find(&pc.entity_id) {
+        ctx.db.offline_transforms().try_insert(transform)?;
+    }
+    if let Some(stats) = ctx.db.character_stats().character_id().find(&pc.character_id) {
+        ctx.db.offline_character_stats().try_insert(stats)?;
+    }
+
+    ctx.db.entities().entity_id().delete(&pc.entity_id);
+    ctx.db.transforms().entity_id().delete(&pc.entity_id);
+    ctx.db.spawn_state().entity_id().delete(&pc.entity_id);
+    ctx.db.character_owner().entity_id().delete(&pc.entity_id);
+    ctx.db.character_stats().character_id().delete(pc.character_id);
+
+    ctx.db.offline_player_characters().try_insert(pc.clone())?;
+    ctx.db.player_characters().character_id().delete(pc.character_id);
+
+    Ok(())
+}
+
+/// Recreate the live entity, components, and stat block for a character that
+/// was just restored from the offline tables, inverting `stash_character_offline`.
+/// The entity comes back at its original id and the character is flagged for
+/// a fresh client-side spawn.
+fn restore_character_components(
+    ctx: &ReducerContext,
+    pc: &crate::players::PlayerCharacter,
+) -> Result<(), String> {
+    let transform = ctx
+        .db
+        .offline_transforms()
+        .entity_id()
+        .find(&pc.entity_id)
+        .map(|component| component.transform)
+        .ok_or("Offline transform not found")?;
+    ctx.db.offline_transforms().entity_id().delete(&pc.entity_id);
+
+    ctx.db.entities().try_insert(crate::entities::Entity {
+        entity_id: pc.entity_id,
+        entity_type: String::from("player_pawn"),
+        transform: transform.clone(),
+        applied_version: 0,
+    })?;
+    ctx.db.transforms().try_insert(TransformComponent {
+        entity_id: pc.entity_id,
+        transform,
+        // Start at 1 so the entity's version-0 state triggers the first sync.
+        transform_version: 1,
+    })?;
+    ctx.db.spawn_state().try_insert(SpawnState {
+        entity_id: pc.entity_id,
+        needs_spawn: true,
+    })?;
+    ctx.db.character_owner().try_insert(CharacterOwner {
+        entity_id: pc.entity_id,
+        player_id: pc.player_id,
+    })?;
+
+    if let Some(stats) = ctx.db.offline_character_stats().character_id().find(&pc.character_id) {
+        ctx.db.offline_character_stats().character_id().delete(pc.character_id);
+        ctx.db.character_stats().try_insert(stats)?;
     }
 
     Ok(())
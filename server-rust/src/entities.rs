@@ -1,8 +1,10 @@
 use crate::types::Transform;
 use spacetimedb::{ReducerContext, Table};
 
-// Bring player_characters trait into scope so ctx.db.player_characters() is available.
-use crate::players::player_characters;
+// Bring the transforms component trait into scope for the generic move pass.
+use crate::components::transforms;
+// Bring the config trait into scope so the tick interval stays reconciled.
+use crate::config::config;
 
 /// Entity table (world objects)
 #[spacetimedb::table(name = entities, public)]
@@ -13,14 +15,30 @@ pub struct Entity {
     pub entity_id: u32,
     pub entity_type: String,
     pub transform: Transform,
+    /// The `TransformComponent::transform_version` last copied onto this
+    /// entity. Starts at 0 so the first sync after spawn always fires.
+    pub applied_version: u64,
 }
 
 /// Periodic reducer that applies PlayerCharacter.transform -> Entity.transform
 #[spacetimedb::reducer]
 pub fn move_all_players(ctx: &ReducerContext, _timer: crate::timers::MoveAllPlayersTimer) -> Result<(), String> {
-    for pc in ctx.db.player_characters().iter() {
-        if let Some(mut entity) = ctx.db.entities().entity_id().find(&pc.entity_id) {
-            entity.transform = pc.transform.clone();
+    // Keep the tick cadence in sync with server-tunable config.
+    if let Some(config) = ctx.db.config().id().find(&0) {
+        crate::config::reschedule_move_timer(ctx, config.move_interval_ms);
+    }
+
+    // Generic over every transform-bearing entity, not just player pawns.
+    // Only write entities whose transform component has advanced since the
+    // last applied version, turning a full O(n) pass into writes proportional
+    // to the number of moving entities.
+    for component in ctx.db.transforms().iter() {
+        if let Some(mut entity) = ctx.db.entities().entity_id().find(&component.entity_id) {
+            if entity.applied_version == component.transform_version {
+                continue;
+            }
+            entity.transform = component.transform.clone();
+            entity.applied_version = component.transform_version;
             ctx.db.entities().entity_id().update(entity);
         } else {
             continue;
@@ -0,0 +1,165 @@
+use crate::types::Transform;
+use spacetimedb::{ReducerContext, Table};
+
+// Bring the generated table helper traits into scope.
+use crate::config::config;
+use crate::players::{players, player_characters};
+use crate::components::transforms;
+
+/// An item that exists in the world, whether held or lying on the floor.
+#[spacetimedb::table(name = items, public)]
+#[derive(Debug, Clone)]
+pub struct Item {
+    #[primary_key]
+    #[auto_inc]
+    pub item_id: u32,
+    pub item_type: String,
+    /// Optional combat modifier carried by the item, if any.
+    pub power: Option<f32>,
+}
+
+/// A held item: maps an item into a character's inventory slot.
+#[spacetimedb::table(name = inventory, public)]
+#[derive(Debug, Clone)]
+pub struct InventoryItem {
+    #[primary_key]
+    pub item_id: u32,
+    #[index(btree)]
+    pub owner_character_id: u32,
+    pub slot: u32,
+}
+
+/// An item dropped in the world, positioned by its `Transform`.
+#[spacetimedb::table(name = floor_items, public)]
+#[derive(Debug, Clone)]
+pub struct FloorItem {
+    #[primary_key]
+    pub item_id: u32,
+    pub transform: Transform,
+}
+
+/// Resolve the caller's primary character id and its live world transform.
+fn caller_character(ctx: &ReducerContext) -> Result<(u32, Transform), String> {
+    let player = ctx
+        .db
+        .players()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or("Player not found")?;
+
+    let pc = ctx
+        .db
+        .player_characters()
+        .player_id()
+        .filter(&player.player_id)
+        .next()
+        .ok_or("Character not found")?;
+
+    let transform = ctx
+        .db
+        .transforms()
+        .entity_id()
+        .find(&pc.entity_id)
+        .ok_or("Character transform not found")?
+        .transform;
+
+    Ok((pc.character_id, transform))
+}
+
+/// Lowest slot index not currently occupied in a character's inventory, so
+/// that dropping from a low slot doesn't collide the next pickup into an
+/// already-occupied higher slot.
+fn lowest_free_slot(ctx: &ReducerContext, character_id: u32) -> u32 {
+    let mut occupied: Vec<u32> = ctx
+        .db
+        .inventory()
+        .owner_character_id()
+        .filter(&character_id)
+        .map(|held| held.slot)
+        .collect();
+    occupied.sort_unstable();
+
+    let mut slot = 0;
+    for taken in occupied {
+        if taken == slot {
+            slot += 1;
+        } else if taken > slot {
+            break;
+        }
+    }
+    slot
+}
+
+/// Squared distance between two positions, reusing the `Transform` x/y/z fields.
+fn distance_sq(a: &Transform, b: &Transform) -> f32 {
+    let dx = a.x - b.x;
+    let dy = a.y - b.y;
+    let dz = a.z - b.z;
+    dx * dx + dy * dy + dz * dz
+}
+
+/// Pick up a floor item, validating that it lies within the configured pickup
+/// radius of the caller's character before moving it into the inventory.
+#[spacetimedb::reducer]
+pub fn pickup_item(ctx: &ReducerContext, item_id: u32) -> Result<(), String> {
+    let (character_id, position) = caller_character(ctx)?;
+
+    let floor = ctx
+        .db
+        .floor_items()
+        .item_id()
+        .find(&item_id)
+        .ok_or("Item is not on the floor")?;
+
+    let radius = ctx
+        .db
+        .config()
+        .id()
+        .find(&0)
+        .map(|config| config.pickup_radius)
+        .ok_or("Config not initialized")?;
+
+    if distance_sq(&position, &floor.transform) > radius * radius {
+        return Err("Item is out of reach".to_string());
+    }
+
+    let slot = lowest_free_slot(ctx, character_id);
+
+    ctx.db.floor_items().item_id().delete(&item_id);
+    ctx.db.inventory().try_insert(InventoryItem {
+        item_id,
+        owner_character_id: character_id,
+        slot,
+    })?;
+
+    crate::players::touch_player(ctx);
+
+    Ok(())
+}
+
+/// Drop a held item onto the floor at the caller's current position.
+#[spacetimedb::reducer]
+pub fn drop_item(ctx: &ReducerContext, item_id: u32) -> Result<(), String> {
+    let (character_id, position) = caller_character(ctx)?;
+
+    let held = ctx
+        .db
+        .inventory()
+        .item_id()
+        .find(&item_id)
+        .ok_or("Item is not held")?;
+
+    if held.owner_character_id != character_id {
+        return Err("Item belongs to another character".to_string());
+    }
+
+    ctx.db.inventory().item_id().delete(&item_id);
+    ctx.db.floor_items().try_insert(FloorItem {
+        item_id,
+        transform: position,
+    })?;
+
+    crate::players::touch_player(ctx);
+
+    Ok(())
+}
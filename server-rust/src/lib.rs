@@ -8,6 +8,11 @@ pub use entities::move_all_players;
 
 pub mod timers;
 pub mod connectivity;
+pub mod config;
+pub mod chat;
+pub mod combat;
+pub mod components;
+pub mod inventory;
 
 pub use types::*;
 pub use players::*;
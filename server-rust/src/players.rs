@@ -1,8 +1,17 @@
 use crate::types::Transform;
-use spacetimedb::{spacetimedb_lib::Identity, ReducerContext, Table};
+use spacetimedb::{spacetimedb_lib::Identity, ReducerContext, Table, Timestamp};
 
 // Bring entities trait into scope because players module inserts entities.
 use crate::entities::entities;
+// Bring the config trait into scope so the spawn point can be read.
+use crate::config::config;
+// Bring the character stats trait into scope so spawns seed a stat block.
+use crate::types::{character_stats, CharacterStats};
+// Bring the component table traits into scope for the ECS-style layout.
+use crate::components::{
+    character_owner, spawn_state, transform_eq, transforms, CharacterOwner, SpawnState,
+    TransformComponent,
+};
 
 /// Player account table (identity is handled by spacetimedb-lib).
 #[spacetimedb::table(name = players, public)]
@@ -15,6 +24,17 @@ pub struct Player {
     #[auto_inc]
     pub player_id: u32,
     pub display_name: String,
+    /// Timestamp of this player's most recent activity; drives the idle sweep.
+    pub last_active: Timestamp,
+}
+
+/// Refresh a player's `last_active` stamp to the current reducer time.
+/// Called by player-driven reducers so the idle sweep can reclaim zombies.
+pub fn touch_player(ctx: &ReducerContext) {
+    if let Some(mut player) = ctx.db.players().identity().find(&ctx.sender) {
+        player.last_active = ctx.timestamp;
+        ctx.db.players().identity().update(player);
+    }
 }
 
 /// PlayerCharacter table. Do NOT derive SATS Serialize/Deserialize here;
@@ -30,8 +50,6 @@ pub struct PlayerCharacter {
     #[index(btree)]
     pub entity_id: u32,
     pub display_name: String,
-    pub transform: Transform,
-    pub needs_spawn: bool,
 }
 
 /// Spawn helpers and reducers
@@ -39,14 +57,13 @@ fn spawn_player_initial_player_character(
     ctx: &ReducerContext,
     player_id: u32,
 ) -> Result<crate::entities::Entity, String> {
-    let position = Transform {
-        x: 0.0,
-        y: 0.0,
-        z: 100.0,
-        yaw: 0.0,
-        pitch: 0.0,
-        roll: 0.0,
-    };
+    let position = ctx
+        .db
+        .config()
+        .id()
+        .find(&0)
+        .map(|config| config.spawn_point)
+        .ok_or("Config not initialized")?;
     spawn_player_character_at(ctx, player_id, position)
 }
 
@@ -59,16 +76,42 @@ fn spawn_player_character_at(
         entity_id: 0,
         entity_type: String::from("player_pawn"),
         transform: position.clone(),
+        applied_version: 0,
     })?;
 
-    ctx.db.player_characters().try_insert(PlayerCharacter {
+    let character = ctx.db.player_characters().try_insert(PlayerCharacter {
         character_id: 0,
         player_id,
         entity_id: entity.entity_id,
         display_name: String::new(),
+    })?;
+
+    // Seed the entity's components rather than storing them on the character.
+    ctx.db.transforms().try_insert(TransformComponent {
+        entity_id: entity.entity_id,
         transform: position,
+        // Start at 1 so the entity's version-0 state triggers the first sync.
+        transform_version: 1,
+    })?;
+    ctx.db.spawn_state().try_insert(SpawnState {
+        entity_id: entity.entity_id,
         needs_spawn: true,
     })?;
+    ctx.db.character_owner().try_insert(CharacterOwner {
+        entity_id: entity.entity_id,
+        player_id,
+    })?;
+
+    ctx.db.character_stats().try_insert(CharacterStats {
+        character_id: character.character_id,
+        strength: 10,
+        intelligence: 10,
+        dexterity: 10.0,
+        health: 100.0,
+        max_health: 100.0,
+        stamina: 100.0,
+        mana: 100.0,
+    })?;
 
     Ok(entity)
 }
@@ -86,6 +129,7 @@ pub fn enter_game(ctx: &ReducerContext, name: String) -> Result<(), String> {
 
     let player_id = player.player_id;
     player.display_name = name;
+    player.last_active = ctx.timestamp;
     ctx.db.players().identity().update(player);
 
     spawn_player_initial_player_character(ctx, player_id)?;
@@ -103,6 +147,7 @@ pub fn respawn(ctx: &ReducerContext) -> Result<(), String> {
         .ok_or("No such player found")?;
 
     spawn_player_initial_player_character(ctx, player.player_id)?;
+    touch_player(ctx);
 
     Ok(())
 }
@@ -116,25 +161,42 @@ pub fn update_player_input(ctx: &ReducerContext, new_transform: Transform) -> Re
         .find(&ctx.sender)
         .ok_or("Player not found")?;
 
-    for mut pc in ctx.db.player_characters().player_id().filter(&player.player_id) {
-        pc.transform = new_transform.clone();
-        ctx.db.player_characters().character_id().update(pc);
+    for owner in ctx.db.character_owner().player_id().filter(&player.player_id) {
+        if let Some(mut transform) = ctx.db.transforms().entity_id().find(&owner.entity_id) {
+            // Skip identical resends so stationary characters don't bump the
+            // version and force a needless sync in `move_all_players`.
+            if transform_eq(&transform.transform, &new_transform) {
+                continue;
+            }
+            transform.transform = new_transform.clone();
+            transform.transform_version += 1;
+            ctx.db.transforms().entity_id().update(transform);
+        }
     }
 
+    touch_player(ctx);
+
     Ok(())
 }
 
 #[spacetimedb::reducer]
 pub fn player_spawned(ctx: &ReducerContext, character_id: u32) -> Result<(), String> {
-    let mut pc = ctx
+    let pc = ctx
         .db
         .player_characters()
         .character_id()
         .find(&character_id)
         .ok_or("Character not found")?;
-    
-    pc.needs_spawn = false;
-    ctx.db.player_characters().character_id().update(pc);
-    
+
+    let mut spawn = ctx
+        .db
+        .spawn_state()
+        .entity_id()
+        .find(&pc.entity_id)
+        .ok_or("Spawn state not found")?;
+    spawn.needs_spawn = false;
+    ctx.db.spawn_state().entity_id().update(spawn);
+    touch_player(ctx);
+
     Ok(())
 }
\ No newline at end of file
@@ -12,10 +12,62 @@ pub struct MoveAllPlayersTimer {
     pub scheduled_at: spacetimedb::ScheduleAt,
 }
 
-/// Helper to initialize the timer row.
-pub fn default_move_all_players_interval() -> MoveAllPlayersTimer {
+/// Build a `ScheduleAt::Interval` from a millisecond cadence.
+pub fn interval_at(ms: u64) -> ScheduleAt {
+    ScheduleAt::Interval(Duration::from_millis(ms).into())
+}
+
+/// Timer table for the idle-player sweep.
+#[spacetimedb::table(name = idle_sweep_timer, scheduled(crate::connectivity::idle_sweep))]
+pub struct IdleSweepTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: spacetimedb::ScheduleAt,
+}
+
+/// Helper to initialize the idle-sweep timer (runs every 30s).
+pub fn default_idle_sweep_interval() -> IdleSweepTimer {
+    IdleSweepTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_secs(30).into()),
+    }
+}
+
+/// Extract the millisecond cadence from an interval schedule, if it is one.
+pub fn interval_millis(at: &ScheduleAt) -> Option<u64> {
+    match at {
+        ScheduleAt::Interval(duration) => Some((duration.to_micros() / 1000) as u64),
+        _ => None,
+    }
+}
+
+/// Timer table for pruning expired chat messages.
+#[spacetimedb::table(name = prune_messages_timer, scheduled(crate::chat::prune_messages))]
+pub struct PruneMessagesTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: spacetimedb::ScheduleAt,
+}
+
+/// Helper to initialize the message-pruning timer (runs every 60s).
+pub fn default_prune_messages_interval() -> PruneMessagesTimer {
+    PruneMessagesTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_secs(60).into()),
+    }
+}
+
+/// Helper to build the timer row for a given tick interval.
+pub fn move_all_players_interval(ms: u64) -> MoveAllPlayersTimer {
     MoveAllPlayersTimer {
         scheduled_id: 0,
-        scheduled_at: ScheduleAt::Interval(Duration::from_millis(50).into()),
+        scheduled_at: interval_at(ms),
     }
+}
+
+/// Helper to initialize the timer row with the default 50ms cadence.
+pub fn default_move_all_players_interval() -> MoveAllPlayersTimer {
+    move_all_players_interval(50)
 }
\ No newline at end of file
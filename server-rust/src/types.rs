@@ -1,4 +1,3 @@
-use spacetimedb_sats::{Serialize, Deserialize}; // SATS derives (crate name in Cargo.toml: spacetimedb-sats)
 use spacetimedb::SpacetimeType;
 
 /// Transform must implement SpacetimeType to be used as table columns and reducer args.
@@ -13,14 +12,19 @@ pub struct Transform {
     pub roll: f32,
 }
 
-/// CharacterStats is a helper structure (not a table column in this layout).
-/// If you need to serialize it with SATS, derive SATS here.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Per-character stat block, keyed to match `PlayerCharacter::character_id`.
+/// The table macro supplies the required SATS implementations.
+#[spacetimedb::table(name = character_stats, public)]
+#[spacetimedb::table(name = offline_character_stats)]
+#[derive(Debug, Clone)]
 pub struct CharacterStats {
+    #[primary_key]
+    pub character_id: u32,
     pub strength: u32,
     pub intelligence: u64,
     pub dexterity: f32,
     pub health: f32,
+    pub max_health: f32,
     pub stamina: f32,
     pub mana: f32,
 }
\ No newline at end of file